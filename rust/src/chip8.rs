@@ -3,15 +3,23 @@
 use anyhow::{bail, Context, Result};
 use rand::Rng;
 use std::fs;
+use std::io::{self, Write};
 use std::path::Path;
 use std::time::{Duration, Instant};
 
-use crate::display::Display;
+use crate::debugger::Debugger;
+use crate::display::Renderer;
 use crate::enums::Instr::*;
 use crate::enums::*;
+use crate::quirks::Quirks;
+use crate::state::{State, STATE_VERSION};
 
-pub const CHIP8_WIDTH: usize = 64;
-pub const CHIP8_HEIGHT: usize = 32;
+// Hi-res (SCHIP) dimensions; the active `width`/`height` default to the
+// low-res CHIP-8 quarter of this until `LOW`/`HIGH` toggles it.
+pub const CHIP8_WIDTH: usize = 128;
+pub const CHIP8_HEIGHT: usize = 64;
+pub const CHIP8_WIDTH_LO: usize = CHIP8_WIDTH / 2;
+pub const CHIP8_HEIGHT_LO: usize = CHIP8_HEIGHT / 2;
 const DIGITS: [u8; 80] = [
     0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
     0x20, 0x60, 0x20, 0x20, 0x70, // 1
@@ -31,9 +39,22 @@ const DIGITS: [u8; 80] = [
     0xF0, 0x80, 0xF0, 0x80, 0x80, // F
 ];
 const DIGITS_LOC: u16 = 0;
-const CLOCK_HZ: u64 = 1000;
-const LIMIT_FREQ: bool = true;
-const DEBUG: bool = false;
+// SCHIP hi-res font: 10 bytes/digit, for the digits 0-9 only.
+const DIGITS_HI: [u8; 100] = [
+    0x3C, 0x7E, 0xE7, 0xC3, 0xC3, 0xC3, 0xC3, 0xE7, 0x7E, 0x3C, // 0
+    0x18, 0x38, 0x58, 0x18, 0x18, 0x18, 0x18, 0x18, 0x18, 0x3C, // 1
+    0x3E, 0x7F, 0xC3, 0x06, 0x0C, 0x18, 0x30, 0x60, 0xFF, 0xFF, // 2
+    0x3C, 0x7E, 0xC3, 0x03, 0x0E, 0x0E, 0x03, 0xC3, 0x7E, 0x3C, // 3
+    0x06, 0x0E, 0x1E, 0x36, 0x66, 0xC6, 0xFF, 0xFF, 0x06, 0x06, // 4
+    0xFF, 0xFF, 0xC0, 0xC0, 0xFC, 0xFE, 0x03, 0xC3, 0x7E, 0x3C, // 5
+    0x3E, 0x7C, 0xC0, 0xC0, 0xFC, 0xFE, 0xC3, 0xC3, 0x7E, 0x3C, // 6
+    0xFF, 0xFF, 0x03, 0x06, 0x0C, 0x18, 0x30, 0x60, 0x60, 0x60, // 7
+    0x3C, 0x7E, 0xC3, 0xC3, 0x7E, 0x7E, 0xC3, 0xC3, 0x7E, 0x3C, // 8
+    0x3C, 0x7E, 0xC3, 0xC3, 0x7F, 0x3F, 0x03, 0x03, 0x7E, 0x3C, // 9
+];
+const DIGITS_HI_LOC: u16 = DIGITS_LOC + 80;
+const FRAME_HZ: u64 = 60;
+const SAVE_STATE_PATH: &str = "chip8.sav";
 
 pub struct Chip8 {
     start: u16,
@@ -49,12 +70,31 @@ pub struct Chip8 {
     ST: u8,
 
     keyboard: [bool; 16],
-    screen: [[bool; CHIP8_WIDTH]; CHIP8_HEIGHT],
-    display: Display,
+    hires: bool,
+    width: usize,
+    height: usize,
+    screen: Vec<Vec<bool>>,
+    rpl: [u8; 8],
+    draw_dirty: bool,
+    quirks: Quirks,
+    clock_hz: u64,
+    limit_freq: bool,
+    debugger: Option<Debugger>,
+    show_speed: bool,
+    display: Box<dyn Renderer>,
 }
 
 impl Chip8 {
-    pub fn new(loc: &str) -> Result<Self> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        loc: &str,
+        quirks: Quirks,
+        clock_hz: u64,
+        limit_freq: bool,
+        debug: bool,
+        show_speed: bool,
+        display: Box<dyn Renderer>,
+    ) -> Result<Self> {
         let path = Path::new(loc);
         let binary = fs::read(&path)
             .with_context(|| format!("Couldn't read file `{}`", path.to_str().unwrap()))?;
@@ -72,72 +112,257 @@ impl Chip8 {
             ST: 0,
 
             keyboard: [false; 16],
-            screen: [[false; CHIP8_WIDTH]; CHIP8_HEIGHT],
-            display: Display::new(),
+            hires: false,
+            width: CHIP8_WIDTH_LO,
+            height: CHIP8_HEIGHT_LO,
+            screen: vec![vec![false; CHIP8_WIDTH_LO]; CHIP8_HEIGHT_LO],
+            rpl: [0; 8],
+            draw_dirty: true,
+            quirks,
+            clock_hz,
+            limit_freq,
+            debugger: debug.then(Debugger::new),
+            show_speed,
+            display,
         };
         let s = c.start as usize;
         let digits_offset = DIGITS_LOC as usize;
         c.RAM[digits_offset..digits_offset + 80].clone_from_slice(&DIGITS);
+        let digits_hi_offset = DIGITS_HI_LOC as usize;
+        c.RAM[digits_hi_offset..digits_hi_offset + 100].clone_from_slice(&DIGITS_HI);
         c.RAM[s..s + binary.len()].clone_from_slice(&binary);
         Ok(c)
     }
 
+    // Snapshot the full machine state to `path` as a versioned bincode blob.
+    pub fn save_state(&self, path: &str) -> Result<()> {
+        let state = State {
+            version: STATE_VERSION,
+            ram: self.RAM.to_vec(),
+            v: self.V,
+            stack: self.stack,
+            pc: self.PC,
+            i: self.I,
+            sp: self.SP,
+            dt: self.DT,
+            st: self.ST,
+            keyboard: self.keyboard,
+            rpl: self.rpl,
+            hires: self.hires,
+            width: self.width,
+            height: self.height,
+            screen: self.screen.clone(),
+        };
+        let bytes = bincode::serialize(&state).context("Couldn't serialize save state")?;
+        fs::write(path, bytes).with_context(|| format!("Couldn't write save state `{}`", path))?;
+        Ok(())
+    }
+
+    // Restore a machine state previously written by `save_state`.
+    pub fn load_state(&mut self, path: &str) -> Result<()> {
+        let bytes =
+            fs::read(path).with_context(|| format!("Couldn't read save state `{}`", path))?;
+        let state: State = bincode::deserialize(&bytes).context("Couldn't parse save state")?;
+        if state.version != STATE_VERSION {
+            bail!(
+                "Save state `{}` is version {}, expected {}",
+                path,
+                state.version,
+                STATE_VERSION
+            );
+        }
+        self.RAM.copy_from_slice(&state.ram);
+        self.V = state.v;
+        self.stack = state.stack;
+        self.PC = state.pc;
+        self.I = state.i;
+        self.SP = state.sp;
+        self.DT = state.dt;
+        self.ST = state.st;
+        self.keyboard = state.keyboard;
+        self.rpl = state.rpl;
+        self.hires = state.hires;
+        self.width = state.width;
+        self.height = state.height;
+        self.screen = state.screen;
+        self.draw_dirty = true;
+        Ok(())
+    }
+
     pub fn run(&mut self) -> Result<()> {
         self.PC = self.start;
-        let mut timer = Instant::now();
-        let frametime = match LIMIT_FREQ {
-            true => Some(Duration::from_nanos(1000000000 / CLOCK_HZ)),
-            false => None,
-        };
+        let cycles_per_frame = (self.clock_hz / FRAME_HZ).max(1);
+        let timer_period = Duration::from_nanos(1_000_000_000 / FRAME_HZ);
+        let frametime = self.limit_freq.then_some(timer_period);
+
+        let mut speed_timer = Instant::now();
+        let mut cycles_this_second: u64 = 0;
+        // Drives DT/ST decrements off real elapsed time rather than loop
+        // iterations, so they stay at 60Hz even when --turbo lets the outer
+        // loop run unthrottled.
+        let mut last_timer_tick = Instant::now();
 
         loop {
             let start_time = Instant::now();
 
-            // Fetch the next two bytes from RAM, and queue up what
-            // instruction to run next. Based on the name of the instruction,
-            // if the instr modifies the PC directly, don't auto-increment it.
-            // Then, run the instruction and invoke a draw call.
-            let opcode = self.fetch_instr(self.PC);
-            let instr = self.parse_instr(opcode)?;
-            if DEBUG {
-                println!(
-                    "{:04x} {:04x} {: <13} | {}",
-                    self.PC,
-                    opcode,
-                    instr_name(&instr),
-                    self.get_state()
-                );
+            // Run a batch of instructions per frame instead of one, so that
+            // instruction throughput can be tuned independently of the
+            // 60Hz draw/input/timer cadence below.
+            for _ in 0..cycles_per_frame {
+                let opcode = self.fetch_instr(self.PC);
+                let instr = self.parse_instr(opcode)?;
+                if self.debugger.is_some() {
+                    self.debugger_prompt(opcode, &instr);
+                }
+                match instr {
+                    JP(_, _) | CALL(_) | RET => (),
+                    _ => self.PC += 2,
+                };
+                if !self.run_instr(instr) {
+                    return Ok(());
+                }
+                cycles_this_second += 1;
             }
-            match instr {
-                JP(_, _) | CALL(_) | RET => (),
-                _ => self.PC += 2,
-            };
+
             self.poll_keyboard();
-            self.run_instr(instr);
-            self.display.draw(&self.screen);
 
-            // If enough time has passed, invoke a decrement of DT and ST.
-            // These should decrement at 60Hz if they have values > 0.
-            if timer.elapsed() > Duration::from_millis(1000 / 60) {
+            // These decrement at 60Hz if they have values > 0, regardless of
+            // how often the outer loop itself iterates.
+            while last_timer_tick.elapsed() >= timer_period {
                 if self.DT > 0 {
                     self.DT -= 1;
                 }
                 if self.ST > 0 {
                     self.ST -= 1;
+                    self.display.set_sound(self.ST > 0);
                 }
-                timer = Instant::now();
+                last_timer_tick += timer_period;
             }
 
-            // If LIMIT_FREQ was set, this will be Some(). This will then sleep
+            // Only push to the canvas if the framebuffer actually changed
+            // since the last frame, to avoid needless redraws.
+            if self.draw_dirty {
+                self.display.draw(&self.screen, self.hires);
+                self.draw_dirty = false;
+            }
+
+            // If limit_freq was set, this will be Some(). This will then sleep
             // so that the total time for the loop is equal to `frametime`.
             if let Some(total) = frametime {
                 let elapsed = start_time.elapsed();
                 if elapsed < total {
                     std::thread::sleep(total - elapsed);
-                } else if DEBUG {
+                } else if self.debugger.is_some() {
                     println!("Frame time: {:?} > {:?}", elapsed, total);
                 }
             }
+
+            // -T: report the emulator's actual speed as a percentage of the
+            // target clock, once every real second.
+            if self.show_speed {
+                let elapsed = speed_timer.elapsed();
+                if elapsed >= Duration::from_secs(1) {
+                    let pct =
+                        100.0 * cycles_this_second as f64 / (self.clock_hz as f64 * elapsed.as_secs_f64());
+                    println!("{:.1}% of target speed", pct);
+                    cycles_this_second = 0;
+                    speed_timer = Instant::now();
+                }
+            }
+        }
+    }
+
+    // Pause before executing `instr` if a breakpoint at the current PC was
+    // just hit, or if the stepper is already paused, and run an interactive
+    // command loop over stdin until the user steps or continues.
+    fn debugger_prompt(&mut self, opcode: u16, instr: &Instr) {
+        let debugger = self.debugger.as_mut().unwrap();
+        if debugger.breakpoints.contains(&self.PC) {
+            debugger.paused = true;
+        }
+        if !debugger.paused {
+            return;
+        }
+        println!(
+            "{:04x} {:04x} {: <13} | {}",
+            self.PC,
+            opcode,
+            instr_name(instr),
+            self.get_state()
+        );
+        loop {
+            print!("(debug) ");
+            let _ = io::stdout().flush();
+            let mut line = String::new();
+            if io::stdin().read_line(&mut line).unwrap_or(0) == 0 {
+                std::process::exit(0);
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            match tokens.as_slice() {
+                ["s"] | ["step"] => break,
+                ["c"] | ["continue"] => {
+                    self.debugger.as_mut().unwrap().paused = false;
+                    break;
+                }
+                ["b", addr] | ["break", addr] => match u16::from_str_radix(addr, 16) {
+                    Ok(addr) => {
+                        self.debugger.as_mut().unwrap().breakpoints.insert(addr);
+                        println!("Breakpoint set at {:04x}", addr);
+                    }
+                    Err(_) => println!("Invalid address: {}", addr),
+                },
+                ["d", addr, len] | ["dump", addr, len] => {
+                    match (u16::from_str_radix(addr, 16), len.parse::<usize>()) {
+                        (Ok(addr), Ok(len)) => self.dump_ram(addr, len),
+                        _ => println!("Usage: dump <hex addr> <len>"),
+                    }
+                }
+                ["r"] | ["regs"] => {
+                    println!("PC={:04x} I={:04x} SP={}", self.PC, self.I, self.SP);
+                    println!("V={:?}", self.V);
+                    println!("stack={:?}", &self.stack[..(self.SP + 1) as usize]);
+                }
+                ["v", idx] => match idx.parse::<usize>() {
+                    Ok(idx) if idx < 0x10 => println!("V{:x} = {:02x}", idx, self.V[idx]),
+                    _ => println!("Invalid register: {}", idx),
+                },
+                ["v", idx, val] => {
+                    match (idx.parse::<usize>(), u8::from_str_radix(val, 16)) {
+                        (Ok(idx), Ok(val)) if idx < 0x10 => {
+                            self.V[idx] = val;
+                            println!("V{:x} = {:02x}", idx, val);
+                        }
+                        _ => println!("Usage: v <index 0-15> <hex value>"),
+                    }
+                }
+                ["i"] => println!("I = {:04x}", self.I),
+                ["i", val] => match u16::from_str_radix(val, 16) {
+                    Ok(val) => {
+                        self.I = val;
+                        println!("I = {:04x}", val);
+                    }
+                    Err(_) => println!("Invalid value: {}", val),
+                },
+                ["q"] | ["quit"] => std::process::exit(0),
+                [] => (),
+                _ => println!(
+                    "commands: s[tep], c[ontinue], b[reak] <hex addr>, \
+                     d[ump] <hex addr> <len>, r[egs], v <idx> [hex val], \
+                     i [hex val], q[uit]"
+                ),
+            }
+        }
+    }
+
+    fn dump_ram(&self, addr: u16, len: usize) {
+        let addr = (addr as usize).min(self.RAM.len());
+        let end = (addr + len).min(self.RAM.len());
+        for (i, chunk) in self.RAM[addr..end].chunks(16).enumerate() {
+            print!("{:04x}:", addr + i * 16);
+            for byte in chunk {
+                print!(" {:02x}", byte);
+            }
+            println!();
         }
     }
 
@@ -157,9 +382,25 @@ impl Chip8 {
     // keyboard value corresponding to it, otherwise return None
     fn poll_keyboard(&mut self) -> Option<(u8, bool)> {
         let (key, v) = self.display.poll_events()?;
-        let key_val = self.get_key(&key[..])?;
-        self.keyboard[key_val as usize] = v;
-        Some((key_val, v))
+        match key.as_str() {
+            // F5/F9 mirror the usual emulator save-state/load-state hotkeys.
+            // Called between instruction batches, so this never fires
+            // mid-draw; the one exception is Fx0A (LD Vx, K), which polls
+            // from inside the instruction while it waits for a key.
+            "F5" if v => {
+                let _ = self.save_state(SAVE_STATE_PATH);
+                None
+            }
+            "F9" if v => {
+                let _ = self.load_state(SAVE_STATE_PATH);
+                None
+            }
+            _ => {
+                let key_val = self.get_key(&key[..])?;
+                self.keyboard[key_val as usize] = v;
+                Some((key_val, v))
+            }
+        }
     }
 
     fn get_key(&self, key: &str) -> Option<u8> {
@@ -204,8 +445,14 @@ impl Chip8 {
         let kk: u8 = instr as u8;
         let nnn: u16 = instr & 0x0FFF;
         let parsed_instr = match nibbles {
+            [0, 0, 0xC, n] => SCD(n),
             [0, 0, 0xE, 0] => CLS,
             [0, 0, 0xE, 0xE] => RET,
+            [0, 0, 0xF, 0xB] => SCR,
+            [0, 0, 0xF, 0xC] => SCL,
+            [0, 0, 0xF, 0xD] => EXIT,
+            [0, 0, 0xF, 0xE] => LOW,
+            [0, 0, 0xF, 0xF] => HIGH,
             [1, _, _, _] => JP(nnn, JPMode::NoOffset),
             [2, _, _, _] => CALL(nnn),
             [3, x, _, _] => SE(x, SEMode::Imm8(kk)),
@@ -219,9 +466,9 @@ impl Chip8 {
             [8, x, y, 3] => XOR(x, y),
             [8, x, y, 4] => ADD(x, ADDMode::Reg(y)),
             [8, x, y, 5] => SUB(x, y),
-            [8, x, _, 6] => SHR(x),
+            [8, x, y, 6] => SHR(x, y),
             [8, x, y, 7] => SUBN(x, y),
-            [8, x, _, 0xE] => SHL(x),
+            [8, x, y, 0xE] => SHL(x, y),
             [9, x, y, 0] => SNE(x, SEMode::Reg(y)),
             [0xA, _, _, _] => LD(0, LDMode::Imm12(nnn)),
             [0xB, _, _, _] => JP(nnn, JPMode::Offset),
@@ -236,14 +483,19 @@ impl Chip8 {
             [0xF, x, 1, 0xE] => ADD(x, ADDMode::ToI),
             [0xF, x, 2, 9] => LD(x, LDMode::F),
             [0xF, x, 3, 3] => LD(x, LDMode::B),
+            [0xF, x, 3, 0] => LD(x, LDMode::HiF),
             [0xF, x, 5, 5] => LD(x, LDMode::ToI),
             [0xF, x, 6, 5] => LD(x, LDMode::FromI),
+            [0xF, x, 7, 5] => LD(x, LDMode::ToRPL),
+            [0xF, x, 8, 5] => LD(x, LDMode::FromRPL),
             _ => bail!("INVALID INSTRUCTION: {:04x}", instr),
         };
         Ok(parsed_instr)
     }
 
-    fn run_instr(&mut self, instr: Instr) {
+    // Execute a single instruction. Returns false if the program requested
+    // an immediate, clean stop (SCHIP's `EXIT`), true otherwise.
+    fn run_instr(&mut self, instr: Instr) -> bool {
         let I = self.I as usize;
         match instr {
             // Arithmetic
@@ -253,7 +505,10 @@ impl Chip8 {
                 LDMode::Reg(y) => self.V[x] = self.V[y],
                 LDMode::FromDT => self.V[x] = self.DT,
                 LDMode::DT => self.DT = self.V[x],
-                LDMode::ST => self.ST = self.V[x],
+                LDMode::ST => {
+                    self.ST = self.V[x];
+                    self.display.set_sound(self.ST > 0);
+                }
                 LDMode::K => loop {
                     if let Some((key_val, true)) = self.poll_keyboard() {
                         self.V[x] = key_val as u8;
@@ -265,8 +520,29 @@ impl Chip8 {
                     let B = [self.V[x] / 100, (self.V[x] % 100) / 10, self.V[x] % 10];
                     self.RAM[I..I + 3].copy_from_slice(&B);
                 }
-                LDMode::ToI => self.RAM[I..I + x + 1].copy_from_slice(&self.V[..x + 1]),
-                LDMode::FromI => self.V[..x + 1].copy_from_slice(&self.RAM[I..I + x + 1]),
+                LDMode::ToI => {
+                    self.RAM[I..I + x + 1].copy_from_slice(&self.V[..x + 1]);
+                    if self.quirks.load_store_increments_i {
+                        self.I += x as u16 + 1;
+                    }
+                }
+                LDMode::FromI => {
+                    self.V[..x + 1].copy_from_slice(&self.RAM[I..I + x + 1]);
+                    if self.quirks.load_store_increments_i {
+                        self.I += x as u16 + 1;
+                    }
+                }
+                LDMode::HiF => self.I = DIGITS_HI_LOC + 10 * self.V[x] as u16,
+                // RPL flags only exist for V0-V7; real SCHIP interpreters
+                // clamp x instead of touching registers beyond that.
+                LDMode::ToRPL => {
+                    let x = x.min(self.rpl.len() - 1);
+                    self.rpl[..x + 1].copy_from_slice(&self.V[..x + 1]);
+                }
+                LDMode::FromRPL => {
+                    let x = x.min(self.rpl.len() - 1);
+                    self.V[..x + 1].copy_from_slice(&self.rpl[..x + 1]);
+                }
             },
             ADD(x, mode) => match mode {
                 ADDMode::Imm8(kk) => self.V[x] = self.V[x].wrapping_add(kk),
@@ -279,23 +555,50 @@ impl Chip8 {
             },
             SUB(x, y) => self.sub(x, y),
             SUBN(x, y) => self.sub(y, x),
-            OR(x, y) => self.V[x] |= self.V[y],
-            AND(x, y) => self.V[x] &= self.V[y],
-            XOR(x, y) => self.V[x] ^= self.V[y],
-            SHR(x) => {
-                self.V[0xF] = self.V[x] & 1;
-                self.V[x] >>= 1;
+            OR(x, y) => {
+                self.V[x] |= self.V[y];
+                self.reset_vf_for_logic();
+            }
+            AND(x, y) => {
+                self.V[x] &= self.V[y];
+                self.reset_vf_for_logic();
+            }
+            XOR(x, y) => {
+                self.V[x] ^= self.V[y];
+                self.reset_vf_for_logic();
             }
-            SHL(x) => {
-                self.V[0xF] = ((self.V[x] & 0x80) > 0) as u8;
-                self.V[x] <<= 1
+            SHR(x, y) => {
+                let val = if self.quirks.shift_uses_vy {
+                    self.V[y]
+                } else {
+                    self.V[x]
+                };
+                self.V[x] = val >> 1;
+                self.V[0xF] = val & 1;
+            }
+            SHL(x, y) => {
+                let val = if self.quirks.shift_uses_vy {
+                    self.V[y]
+                } else {
+                    self.V[x]
+                };
+                self.V[x] = val << 1;
+                self.V[0xF] = ((val & 0x80) > 0) as u8;
             }
             RND(x, kk) => self.V[x] = self.rng.gen::<u8>() & kk,
 
             // Control Flow
             RET => self.PC = self.pop(),
             JP(nnn, JPMode::NoOffset) => self.PC = nnn,
-            JP(nnn, JPMode::Offset) => self.PC = nnn + self.V[0] as u16,
+            JP(nnn, JPMode::Offset) => {
+                let x = ((nnn >> 8) & 0xF) as usize;
+                let base = if self.quirks.jump_uses_v0 {
+                    self.V[0]
+                } else {
+                    self.V[x]
+                };
+                self.PC = nnn + base as u16;
+            }
             CALL(nnn) => {
                 self.push(self.PC + 2);
                 self.PC = nnn;
@@ -309,8 +612,60 @@ impl Chip8 {
 
             // Drawing
             DRW(x, y, n) => self.draw(x, y, n),
-            CLS => self.screen = [[false; CHIP8_WIDTH]; CHIP8_HEIGHT],
+            CLS => {
+                self.screen = vec![vec![false; self.width]; self.height];
+                self.draw_dirty = true;
+            }
+
+            // SCHIP
+            SCD(n) => self.scroll_down(n),
+            SCR => self.scroll_horizontal(4),
+            SCL => self.scroll_horizontal(-4),
+            EXIT => return false,
+            LOW => self.set_resolution(false),
+            HIGH => self.set_resolution(true),
         }
+        true
+    }
+
+    // SCHIP: switch between the 64x32 low-res and 128x64 hi-res screen,
+    // clearing the framebuffer to the new dimensions.
+    fn set_resolution(&mut self, hires: bool) {
+        self.hires = hires;
+        self.width = if hires { CHIP8_WIDTH } else { CHIP8_WIDTH_LO };
+        self.height = if hires { CHIP8_HEIGHT } else { CHIP8_HEIGHT_LO };
+        self.screen = vec![vec![false; self.width]; self.height];
+        self.draw_dirty = true;
+    }
+
+    // SCHIP 00Cn: scroll the screen down by n rows, shifting in blank rows
+    // at the top.
+    fn scroll_down(&mut self, n: usize) {
+        let blank = vec![false; self.width];
+        self.screen.truncate(self.height - n.min(self.height));
+        for _ in 0..n.min(self.height) {
+            self.screen.insert(0, blank.clone());
+        }
+        self.draw_dirty = true;
+    }
+
+    // SCHIP 00FB/00FC: scroll the screen right/left by 4 pixels, shifting
+    // in blank columns at the vacated edge.
+    fn scroll_horizontal(&mut self, dx: isize) {
+        for row in self.screen.iter_mut() {
+            let len = row.len();
+            if dx > 0 {
+                let shift = (dx as usize).min(len);
+                row.rotate_right(shift);
+                row[..shift].fill(false);
+            } else {
+                let shift = (-dx) as usize;
+                let shift = shift.min(len);
+                row.rotate_left(shift);
+                row[len - shift..].fill(false);
+            }
+        }
+        self.draw_dirty = true;
     }
 
     // Subtract V[y] from V[x], and set VF if NO BORROW occurs
@@ -320,26 +675,74 @@ impl Chip8 {
         self.V[0xF] = !borrow as u8;
     }
 
+    // COSMAC VIP quirk: OR/AND/XOR clobber VF as a side effect.
+    fn reset_vf_for_logic(&mut self) {
+        if self.quirks.vf_reset_on_logic {
+            self.V[0xF] = 0;
+        }
+    }
+
+    // Map a sprite-local coordinate onto the screen, either wrapping it
+    // around to the other side or clipping it (returning None) depending
+    // on the active quirk.
+    fn wrap_or_clip(&self, val: usize, dim: usize) -> Option<usize> {
+        if val < dim {
+            Some(val)
+        } else if self.quirks.clip_sprites {
+            None
+        } else {
+            Some(val % dim)
+        }
+    }
+
     fn skip(&mut self, expr: bool) {
         if expr {
             self.PC += 2;
         }
     }
 
-    // Draw an 8xN Sprite at the location (Vx, Vy) on the screen by XORing
-    // the screen with the sprite. Set VF if any set pixels on the screen
-    // are erased during this process. Any pixels that would be drawn out
-    // of bounds are wrapped around to the other side of the screen.
+    // Draw an 8xN Sprite (or, for SCHIP's Dxy0, a 16x16 sprite) at the
+    // location (Vx, Vy) on the screen by XORing the screen with the sprite.
+    // Set VF if any set pixels on the screen are erased during this process.
+    // Any pixels that would be drawn out of bounds are wrapped around to the
+    // other side of the screen, or dropped if the `clip_sprites` quirk is set.
     fn draw(&mut self, x: usize, y: usize, n: usize) {
+        self.draw_dirty = true;
         self.V[0xF] = 0;
-        for j in 0..n {
-            let y = (self.V[y] as usize + j) % CHIP8_HEIGHT;
-            let val = self.RAM[self.I as usize + j];
-            for i in 0..8 {
-                let x = (self.V[x] as usize + i) % CHIP8_WIDTH;
-                let bit = ((val >> (7 - i)) & 1) != 0;
-                self.V[0xF] |= (bit & self.screen[y][x]) as u8;
-                self.screen[y][x] ^= bit;
+        if n == 0 {
+            for j in 0..16 {
+                let y = match self.wrap_or_clip(self.V[y] as usize + j, self.height) {
+                    Some(y) => y,
+                    None => continue,
+                };
+                let row = ((self.RAM[self.I as usize + 2 * j] as u16) << 8)
+                    | self.RAM[self.I as usize + 2 * j + 1] as u16;
+                for i in 0..16 {
+                    let x = match self.wrap_or_clip(self.V[x] as usize + i, self.width) {
+                        Some(x) => x,
+                        None => continue,
+                    };
+                    let bit = ((row >> (15 - i)) & 1) != 0;
+                    self.V[0xF] |= (bit & self.screen[y][x]) as u8;
+                    self.screen[y][x] ^= bit;
+                }
+            }
+        } else {
+            for j in 0..n {
+                let y = match self.wrap_or_clip(self.V[y] as usize + j, self.height) {
+                    Some(y) => y,
+                    None => continue,
+                };
+                let val = self.RAM[self.I as usize + j];
+                for i in 0..8 {
+                    let x = match self.wrap_or_clip(self.V[x] as usize + i, self.width) {
+                        Some(x) => x,
+                        None => continue,
+                    };
+                    let bit = ((val >> (7 - i)) & 1) != 0;
+                    self.V[0xF] |= (bit & self.screen[y][x]) as u8;
+                    self.screen[y][x] ^= bit;
+                }
             }
         }
     }
@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+
+// Tracks breakpoints and the stepper's pause state for `--debug` mode. The
+// interactive command loop itself lives on `Chip8`, since it needs access to
+// machine state (RAM, V, I, the stack) that this struct doesn't hold.
+pub struct Debugger {
+    pub breakpoints: HashSet<u16>,
+    pub paused: bool,
+}
+
+impl Debugger {
+    pub fn new() -> Self {
+        Debugger {
+            breakpoints: HashSet::new(),
+            // Start paused so --debug always drops you into the stepper
+            // before the first instruction executes.
+            paused: true,
+        }
+    }
+}
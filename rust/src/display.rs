@@ -1,3 +1,4 @@
+use sdl2::audio::{AudioCallback, AudioDevice, AudioSpecDesired};
 use sdl2::event::Event;
 use sdl2::keyboard::Keycode;
 use sdl2::pixels::Color;
@@ -5,23 +6,112 @@ use sdl2::rect::Rect;
 use sdl2::render::Canvas;
 use sdl2::video::Window;
 
-use crate::chip8::{CHIP8_HEIGHT, CHIP8_WIDTH};
-const SCALE_FACTOR: usize = 10;
-const SCREEN_WIDTH: usize = CHIP8_WIDTH * SCALE_FACTOR;
-const SCREEN_HEIGHT: usize = CHIP8_HEIGHT * SCALE_FACTOR;
+use crate::chip8::{CHIP8_HEIGHT_LO, CHIP8_WIDTH_LO};
+
+// A backend that can show the framebuffer and report key events. `Chip8`
+// holds one of these behind a `Box<dyn Renderer>` so the SDL2 window and
+// the terminal backend in `terminal.rs` are interchangeable at runtime.
+pub trait Renderer {
+    fn draw(&mut self, screen: &[Vec<bool>], hires: bool);
+    fn poll_events(&mut self) -> Option<(String, bool)>;
+    // Only the SDL2 backend can actually play audio; other backends no-op.
+    fn set_sound(&mut self, _playing: bool) {}
+}
+
+const BEEP_FREQ_HZ: f32 = 440.0;
+const BEEP_VOLUME: f32 = 0.25;
+
+// A 50% duty cycle square wave, generated sample-by-sample so it can be
+// toggled on/off without clicking at the boundary.
+struct SquareWave {
+    phase_inc: f32,
+    phase: f32,
+    volume: f32,
+}
+
+impl AudioCallback for SquareWave {
+    type Channel = f32;
+
+    fn callback(&mut self, out: &mut [f32]) {
+        for x in out.iter_mut() {
+            *x = if self.phase < 0.5 {
+                self.volume
+            } else {
+                -self.volume
+            };
+            self.phase = (self.phase + self.phase_inc) % 1.0;
+        }
+    }
+}
+
+// Plays a constant square wave tone while `ST > 0`. Pausing/resuming the
+// underlying device (rather than tearing it down) keeps the toggle click-free.
+pub struct Beeper {
+    device: Option<AudioDevice<SquareWave>>,
+    playing: bool,
+}
+
+impl Beeper {
+    fn new(audio_subsystem: &sdl2::AudioSubsystem, muted: bool) -> Self {
+        let device = if muted {
+            None
+        } else {
+            let spec = AudioSpecDesired {
+                freq: Some(44100),
+                channels: Some(1),
+                samples: None,
+            };
+            audio_subsystem
+                .open_playback(None, &spec, |spec| SquareWave {
+                    phase_inc: BEEP_FREQ_HZ / spec.freq as f32,
+                    phase: 0.0,
+                    volume: BEEP_VOLUME,
+                })
+                .ok()
+        };
+        Beeper {
+            device,
+            playing: false,
+        }
+    }
+
+    // Start or stop playback. No-op if already in the requested state, or if
+    // audio was muted at startup.
+    pub fn set_playing(&mut self, playing: bool) {
+        if playing == self.playing {
+            return;
+        }
+        if let Some(device) = &self.device {
+            if playing {
+                device.resume();
+            } else {
+                device.pause();
+            }
+        }
+        self.playing = playing;
+    }
+}
 
 pub struct Display {
     event_pump: sdl2::EventPump,
     canvas: Canvas<Window>,
+    beeper: Beeper,
+    scale: usize,
 }
 
 impl Display {
-    pub fn new() -> Self {
+    pub fn new(scale: usize, mute_audio: bool) -> Self {
         let context = sdl2::init().unwrap();
         let video_subsystem = context.video().unwrap();
+        let audio_subsystem = context.audio().unwrap();
 
+        // Sized off the low-res dimensions so a plain CHIP-8 ROM fills the
+        // whole window at full scale; `draw()` halves the per-pixel scale in
+        // hi-res mode to fit the same window.
+        let screen_width = (CHIP8_WIDTH_LO * scale) as u32;
+        let screen_height = (CHIP8_HEIGHT_LO * scale) as u32;
         let window = video_subsystem
-            .window("CHIP8", SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32)
+            .window("CHIP8", screen_width, screen_height)
             .position_centered()
             .build()
             .unwrap();
@@ -29,29 +119,38 @@ impl Display {
         return Display {
             event_pump: context.event_pump().unwrap(),
             canvas: window.into_canvas().build().unwrap(),
+            beeper: Beeper::new(&audio_subsystem, mute_audio),
+            scale,
         };
     }
+}
+
+impl Renderer for Display {
+    // Start or silence the beeper based on whether the sound timer is active.
+    fn set_sound(&mut self, playing: bool) {
+        self.beeper.set_playing(playing);
+    }
 
-    // Draw pixels to the screen based on the contents of the passed-in array.
-    // We iterate through the array and if a pixel is set, we draw it to the
-    // screen in the correct place and with the correct size. Unset pixels are
-    // not drawn, because the background is already black.
-    pub fn draw(&mut self, x: &[[u8; CHIP8_WIDTH]; CHIP8_HEIGHT]) {
+    // Draw pixels to the screen based on the contents of the passed-in
+    // framebuffer. We iterate through it and if a pixel is set, we draw it
+    // to the screen in the correct place and with the correct size. Unset
+    // pixels are not drawn, because the background is already black. In
+    // hi-res (SCHIP) mode the buffer is twice as wide/tall, so each pixel
+    // is drawn at half the configured `scale` to keep the window size constant.
+    fn draw(&mut self, screen: &[Vec<bool>], hires: bool) {
+        let scale = if hires { self.scale / 2 } else { self.scale };
         self.canvas.set_draw_color(Color::BLACK);
         self.canvas.clear();
-        for i in 0..64 {
-            for j in 0..32 {
-                match x[j][i] {
-                    1 => {
-                        self.canvas.set_draw_color(Color::WHITE);
-                        let _ = self.canvas.fill_rect(Rect::new(
-                            (i * SCALE_FACTOR) as i32,
-                            (j * SCALE_FACTOR) as i32,
-                            SCALE_FACTOR as u32,
-                            SCALE_FACTOR as u32,
-                        ));
-                    }
-                    _ => continue,
+        self.canvas.set_draw_color(Color::WHITE);
+        for (j, row) in screen.iter().enumerate() {
+            for (i, &pixel) in row.iter().enumerate() {
+                if pixel {
+                    let _ = self.canvas.fill_rect(Rect::new(
+                        (i * scale) as i32,
+                        (j * scale) as i32,
+                        scale as u32,
+                        scale as u32,
+                    ));
                 }
             }
         }
@@ -63,7 +162,7 @@ impl Display {
     //      2. If it's a keydown/up event, return the name of the
     //         key as well as the "pressed" state as a bool.
     //      3. If it's anything else, do nothing.
-    pub fn poll_events(&mut self) -> Option<(String, bool)> {
+    fn poll_events(&mut self) -> Option<(String, bool)> {
         for event in self.event_pump.poll_iter() {
             match event {
                 Event::Quit { .. }
@@ -10,8 +10,8 @@ pub enum Instr {
     OR(usize, usize),
     AND(usize, usize),
     XOR(usize, usize),
-    SHL(usize),
-    SHR(usize),
+    SHL(usize, usize),
+    SHR(usize, usize),
     RND(usize, u8),
 
     // Control Flow
@@ -26,6 +26,14 @@ pub enum Instr {
     // Drawing
     DRW(usize, usize, usize),
     CLS,
+
+    // SCHIP
+    SCD(usize),
+    SCR,
+    SCL,
+    EXIT,
+    LOW,
+    HIGH,
 }
 
 pub enum LDMode {
@@ -40,6 +48,9 @@ pub enum LDMode {
     B,
     ToI,
     FromI,
+    HiF,    // SCHIP: point I at the 10-byte hi-res font digit for Vx
+    ToRPL,  // SCHIP: save V0..Vx to the RPL flag registers
+    FromRPL, // SCHIP: load V0..Vx from the RPL flag registers
 }
 
 pub enum ADDMode {
@@ -75,9 +86,9 @@ pub fn instr_name(instr: &Instr) -> &str {
         XOR(x, y) => "XOR Vx, Vy",                 // 8xy3
         ADD(x, ADDMode::Reg(y)) => "ADD Vx, Vy",   // 8xy4
         SUB(x, y) => "SUB Vx, Vy",                 // 8xy5
-        SHR(x) => "SHR Vx",                        // 8xy6
+        SHR(x, y) => "SHR Vx",                     // 8xy6
         SUBN(x, y) => "SUB Vy, Vx",                // 8xy7
-        SHL(x) => "SHL Vx",                        // 8xyE
+        SHL(x, y) => "SHL Vx",                     // 8xyE
         SNE(x, SEMode::Reg(y)) => "SNE Vx, Vy",    // 9xy0
         LD(_, LDMode::Imm12(nnn)) => "LD I, nnn",  // Annn
         JP(nnn, JPMode::Offset) => "JP V0, nnn",   // Bnnn
@@ -94,5 +105,16 @@ pub fn instr_name(instr: &Instr) -> &str {
         LD(x, LDMode::B) => "LD Vx, B",            // Fx33
         LD(x, LDMode::ToI) => "LD [I], Vx",        // Fx55
         LD(x, LDMode::FromI) => "LD Vx, [I]",      // Fx65
+
+        // SCHIP
+        SCD(n) => "SCD n",                         // 00Cn
+        SCR => "SCR",                               // 00FB
+        SCL => "SCL",                               // 00FC
+        EXIT => "EXIT",                             // 00FD
+        LOW => "LOW",                               // 00FE
+        HIGH => "HIGH",                             // 00FF
+        LD(x, LDMode::HiF) => "LD Vx, HF",         // Fx30
+        LD(x, LDMode::ToRPL) => "LD R, Vx",        // Fx75
+        LD(x, LDMode::FromRPL) => "LD Vx, R",      // Fx85
     }
 }
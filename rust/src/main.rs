@@ -1,17 +1,108 @@
 mod chip8;
+mod debugger;
 mod display;
 mod enums;
+mod quirks;
+mod state;
+mod terminal;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 use chip8::Chip8;
-use std::env;
+use clap::{Parser, ValueEnum};
+use display::{Display, Renderer};
+use quirks::Quirks;
+use terminal::TerminalDisplay;
 
-fn main() -> Result<()> {
-    let args: Vec<String> = env::args().collect();
-    if args.len() < 2 {
-        bail!("Please provide a path.");
+/// CHIP-8 / SCHIP emulator
+#[derive(Parser)]
+#[command(name = "chip8")]
+struct Cli {
+    /// Path to the ROM to load
+    rom: String,
+
+    /// Pixel scale factor
+    #[arg(short, long, default_value_t = 10)]
+    scale: usize,
+
+    /// Target CPU clock speed, in Hz
+    #[arg(short, long, default_value_t = 1000)]
+    clock: u64,
+
+    /// Quirks profile to emulate
+    #[arg(short, long, value_enum, default_value_t = Variant::Vip)]
+    variant: Variant,
+
+    /// Run unthrottled instead of at --clock
+    #[arg(long)]
+    turbo: bool,
+
+    /// Start paused in an interactive stepping debugger (pauses again at breakpoints after continuing)
+    #[arg(long)]
+    debug: bool,
+
+    /// Mute the sound timer's audio output
+    #[arg(short = 'a', long)]
+    mute: bool,
+
+    /// Print the emulator's actual speed as a percentage of --clock
+    #[arg(short = 'T', long)]
+    show_speed: bool,
+
+    /// Rendering backend
+    #[arg(short, long, value_enum, default_value_t = Backend::Sdl)]
+    backend: Backend,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Backend {
+    /// An SDL2 window
+    Sdl,
+    /// Half-block Unicode rendering in the current terminal
+    Terminal,
+}
+
+#[derive(Copy, Clone, ValueEnum)]
+enum Variant {
+    /// Original COSMAC VIP semantics
+    Vip,
+    /// CHIP-48 semantics
+    Chip48,
+    /// SUPER-CHIP semantics
+    Schip,
+}
+
+impl From<Variant> for Quirks {
+    fn from(variant: Variant) -> Self {
+        match variant {
+            Variant::Vip => Quirks::default(),
+            Variant::Chip48 | Variant::Schip => Quirks {
+                shift_uses_vy: false,
+                load_store_increments_i: false,
+                jump_uses_v0: false,
+                vf_reset_on_logic: false,
+                clip_sprites: true,
+            },
+        }
     }
-    let mut c8 = Chip8::new(&args[1])?;
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    let display: Box<dyn Renderer> = match cli.backend {
+        Backend::Sdl => Box::new(Display::new(cli.scale, cli.mute)),
+        Backend::Terminal => Box::new(TerminalDisplay::new()),
+    };
+
+    let mut c8 = Chip8::new(
+        &cli.rom,
+        cli.variant.into(),
+        cli.clock,
+        !cli.turbo,
+        cli.debug,
+        cli.show_speed,
+        display,
+    )?;
     c8.run()?;
     Ok(())
 }
@@ -0,0 +1,29 @@
+// CHIP-8 variants disagree on a handful of ambiguous behaviors. Each field
+// here toggles one of them; defaults reproduce standard COSMAC VIP
+// semantics, which later interpreters (CHIP48, SCHIP) diverged from.
+pub struct Quirks {
+    // SHR/SHL read and shift V[y], storing the result in V[x], instead of
+    // shifting V[x] in place.
+    pub shift_uses_vy: bool,
+    // Fx55/Fx65 leave I pointing one past the last register copied.
+    pub load_store_increments_i: bool,
+    // Bnnn jumps to `nnn + V[0]`, instead of `nnn + V[x]` where x is nnn's
+    // high nibble.
+    pub jump_uses_v0: bool,
+    // 8xy1/8xy2/8xy3 (OR/AND/XOR) clear VF afterwards.
+    pub vf_reset_on_logic: bool,
+    // Dxyn clips sprites at the screen edge instead of wrapping them around.
+    pub clip_sprites: bool,
+}
+
+impl Default for Quirks {
+    fn default() -> Self {
+        Quirks {
+            shift_uses_vy: true,
+            load_store_increments_i: true,
+            jump_uses_v0: true,
+            vf_reset_on_logic: true,
+            clip_sprites: false,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+// Bump this if the layout below ever changes, so loading an old save fails
+// fast instead of decoding into garbage.
+pub const STATE_VERSION: u32 = 1;
+
+// A snapshot of everything needed to resume execution exactly where it left
+// off: RAM, registers, the call stack, timers, input state, the
+// framebuffer, and the active resolution.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    pub version: u32,
+    pub ram: Vec<u8>,
+    pub v: [u8; 0x10],
+    pub stack: [u16; 0x10],
+    pub pc: u16,
+    pub i: u16,
+    pub sp: i8,
+    pub dt: u8,
+    pub st: u8,
+    pub keyboard: [bool; 16],
+    pub rpl: [u8; 8],
+    pub hires: bool,
+    pub width: usize,
+    pub height: usize,
+    pub screen: Vec<Vec<bool>>,
+}
@@ -0,0 +1,101 @@
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Color, Print, ResetColor, SetBackgroundColor, SetForegroundColor};
+use crossterm::terminal::{self, ClearType};
+use crossterm::{cursor, execute, queue};
+use std::collections::HashMap;
+use std::io::{stdout, Stdout, Write};
+use std::time::{Duration, Instant};
+
+use crate::display::Renderer;
+
+// Most terminals never send `KeyEventKind::Release` (that requires the
+// Kitty keyboard protocol, which we don't negotiate); instead they repeat
+// `Press` events while a key is held, then go quiet on release. So a key is
+// treated as released once this much time has passed without seeing it again.
+const KEY_RELEASE_TIMEOUT: Duration = Duration::from_millis(150);
+
+// Renders the framebuffer to a TTY using half-block characters: each
+// character cell covers two vertical CHIP-8 pixels, with the top pixel as
+// the foreground color and the bottom pixel as the background color.
+pub struct TerminalDisplay {
+    stdout: Stdout,
+    // Keys currently considered held, and when we last saw an event for them.
+    held: HashMap<String, Instant>,
+}
+
+impl TerminalDisplay {
+    pub fn new() -> Self {
+        let mut stdout = stdout();
+        terminal::enable_raw_mode().unwrap();
+        execute!(stdout, terminal::Clear(ClearType::All), cursor::Hide).unwrap();
+        TerminalDisplay {
+            stdout,
+            held: HashMap::new(),
+        }
+    }
+
+    // If a held key hasn't been refreshed by a new event within the
+    // timeout, synthesize its release.
+    fn expire_held_key(&mut self) -> Option<(String, bool)> {
+        let now = Instant::now();
+        let expired = self
+            .held
+            .iter()
+            .find(|(_, &seen)| now.duration_since(seen) >= KEY_RELEASE_TIMEOUT)
+            .map(|(key, _)| key.clone())?;
+        self.held.remove(&expired);
+        Some((expired, false))
+    }
+}
+
+impl Drop for TerminalDisplay {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(self.stdout, cursor::Show, ResetColor);
+    }
+}
+
+impl Renderer for TerminalDisplay {
+    fn draw(&mut self, screen: &[Vec<bool>], _hires: bool) {
+        let _ = queue!(self.stdout, cursor::MoveTo(0, 0));
+        for y in (0..screen.len()).step_by(2) {
+            for (x, &top) in screen[y].iter().enumerate() {
+                let bottom = screen.get(y + 1).map_or(false, |row| row[x]);
+                let fg = if top { Color::White } else { Color::Black };
+                let bg = if bottom { Color::White } else { Color::Black };
+                let _ = queue!(
+                    self.stdout,
+                    SetForegroundColor(fg),
+                    SetBackgroundColor(bg),
+                    Print('\u{2580}')
+                );
+            }
+            let _ = queue!(self.stdout, ResetColor, Print("\r\n"));
+        }
+        let _ = self.stdout.flush();
+    }
+
+    fn poll_events(&mut self) -> Option<(String, bool)> {
+        if !event::poll(Duration::from_secs(0)).unwrap_or(false) {
+            return self.expire_held_key();
+        }
+        if let Ok(Event::Key(key_event)) = event::read() {
+            if key_event.code == KeyCode::Esc {
+                let _ = terminal::disable_raw_mode();
+                std::process::exit(0);
+            }
+            let name = match key_event.code {
+                KeyCode::Char(c) => c.to_ascii_uppercase().to_string(),
+                KeyCode::F(n) => format!("F{n}"),
+                _ => return None,
+            };
+            if key_event.kind == KeyEventKind::Release {
+                self.held.remove(&name);
+                return Some((name, false));
+            }
+            self.held.insert(name.clone(), Instant::now());
+            return Some((name, true));
+        }
+        None
+    }
+}